@@ -4,22 +4,26 @@ use datafusion::datasource::empty::EmptyTable;
 
 use datafusion::logical_expr::LogicalPlan;
 use datafusion::scalar::ScalarValue;
-use datafusion_expr::Expr as DFExpr;
 use datafusion_expr::logical_plan::JoinConstraint;
 use datafusion_expr::logical_plan::{
-    Join as DFJoin, Limit as DFLimit, Projection as DFProjection,
+    EmptyRelation as DFEmptyRelation, Join as DFJoin, Limit as DFLimit, Projection as DFProjection,
     TableScan as DFTableScan,
 };
+use datafusion_expr::Expr as DFExpr;
 
 use datafusion::datasource::DefaultTableSource;
 
 use dolomite::error::DolomiteResult;
 use dolomite::operator::LogicalOperator::{
-    LogicalJoin, LogicalLimit, LogicalProjection, LogicalScan,
+    LogicalEmptyRelation, LogicalJoin, LogicalLimit, LogicalProjection, LogicalScan,
 };
 use dolomite::operator::Operator::Logical;
 
-use dolomite::operator::{Filter, Limit, LogicalOperator, Projection, TableScan};
+use dolomite::operator::{
+    EmptyRelation, Filter, Join, Limit, LogicalOperator, Projection, TableScan,
+};
+
+use datafusion_expr::and;
 
 use dolomite::plan::{Plan, PlanNode, PlanNodeIdGen};
 
@@ -38,13 +42,46 @@ pub fn from_df_logical(df_plan: &LogicalPlan) -> DolomiteResult<Plan> {
     Ok(Plan::new(Arc::new(root)))
 }
 
-fn plan_node_to_df_logical_plan(plan_node: &PlanNode) -> DolomiteResult<LogicalPlan> {
-    let mut inputs = plan_node
-        .inputs()
-        .iter()
-        .map(|p| plan_node_to_df_logical_plan(p))
-        .collect::<DolomiteResult<Vec<LogicalPlan>>>()?;
+/// Converts `root` and every node under it to a `LogicalPlan`, bottom-up.
+///
+/// This walks the tree with an explicit work stack rather than recursing
+/// structurally: each `PlanNode` is first pushed as `Expand` (which queues
+/// its children, deepest-first, ahead of a matching `Build` marker), and
+/// once all of a node's children have produced a `LogicalPlan` the `Build`
+/// marker pops them off `results` and converts the node itself. A chain of
+/// projections/filters/limits that would blow the call stack via plain
+/// recursion is bounded only by heap-allocated `Vec`s here.
+fn plan_node_to_df_logical_plan(root: &PlanNode) -> DolomiteResult<LogicalPlan> {
+    enum Frame<'a> {
+        Expand(&'a PlanNode),
+        Build(&'a PlanNode, usize),
+    }
+
+    let mut work = vec![Frame::Expand(root)];
+    let mut results: Vec<LogicalPlan> = vec![];
+
+    while let Some(frame) = work.pop() {
+        match frame {
+            Frame::Expand(node) => {
+                work.push(Frame::Build(node, node.inputs().len()));
+                for child in node.inputs().iter().rev() {
+                    work.push(Frame::Expand(child));
+                }
+            }
+            Frame::Build(node, n_children) => {
+                let inputs = results.split_off(results.len() - n_children);
+                results.push(build_df_logical_plan(node, inputs)?);
+            }
+        }
+    }
 
+    Ok(results.pop().unwrap())
+}
+
+fn build_df_logical_plan(
+    plan_node: &PlanNode,
+    mut inputs: Vec<LogicalPlan>,
+) -> DolomiteResult<LogicalPlan> {
     match plan_node.operator() {
         Logical(LogicalProjection(projection)) => {
             let df_projection = DFProjection::try_new_with_schema(
@@ -57,11 +94,29 @@ fn plan_node_to_df_logical_plan(plan_node: &PlanNode) -> DolomiteResult<LogicalP
             Ok(LogicalPlan::Projection(df_projection))
         }
         Logical(LogicalLimit(limit)) => {
+            let skip = if limit.skip() > 0 {
+                Some(Box::new(DFExpr::Literal(ScalarValue::Int64(Some(
+                    limit.skip() as i64,
+                )))))
+            } else {
+                None
+            };
+
+            // `usize::MAX` is the sentinel `df_logical_plan_to_plan_node` uses
+            // for "no LIMIT, OFFSET-only" (see its `Limit` arm below). Map it
+            // back to `fetch: None` instead of letting it wrap to `-1` via
+            // `as i64`.
+            let fetch = if limit.limit() == usize::MAX {
+                None
+            } else {
+                Some(Box::new(DFExpr::Literal(ScalarValue::Int64(Some(
+                    limit.limit() as i64,
+                )))))
+            };
+
             let df_limit = DFLimit {
-                skip: None,
-                fetch: Some(Box::new(DFExpr::Literal(
-					ScalarValue::Int64(Some(limit.limit() as i64))
-				))),
+                skip,
+                fetch,
                 input: Arc::new(inputs.remove(0)),
             };
 
@@ -81,6 +136,14 @@ fn plan_node_to_df_logical_plan(plan_node: &PlanNode) -> DolomiteResult<LogicalP
 
             Ok(LogicalPlan::Join(df_join))
         }
+        Logical(LogicalEmptyRelation(empty)) => {
+            let df_empty = DFEmptyRelation {
+                produce_one_row: empty.produce_one_row(),
+                schema: Arc::new(plan_node.logical_prop().unwrap().schema().clone()),
+            };
+
+            Ok(LogicalPlan::EmptyRelation(df_empty))
+        }
         Logical(LogicalScan(scan)) => {
             let schema = Arc::new(plan_node.logical_prop().unwrap().schema().clone());
             let source = Arc::new(DefaultTableSource::new(Arc::new(EmptyTable::new(
@@ -91,7 +154,7 @@ fn plan_node_to_df_logical_plan(plan_node: &PlanNode) -> DolomiteResult<LogicalP
                 source,
                 projection: None,
                 projected_schema: schema,
-                filters: vec![],
+                filters: scan.filters().to_vec(),
                 fetch: scan.limit(),
             };
 
@@ -101,68 +164,103 @@ fn plan_node_to_df_logical_plan(plan_node: &PlanNode) -> DolomiteResult<LogicalP
     }
 }
 
+/// Converts `root` and every node under it to a `PlanNode`, bottom-up.
+///
+/// Mirrors `plan_node_to_df_logical_plan`'s explicit work stack instead of
+/// recursing structurally. IDs are still handed out in the same pre-order
+/// as the original recursive walk (a node's id is generated the moment it
+/// is expanded, before any of its children are), so existing callers that
+/// depend on id ordering see no change in behavior.
 fn df_logical_plan_to_plan_node(
-    df_plan: &LogicalPlan,
+    root: &LogicalPlan,
     id_gen: &mut PlanNodeIdGen,
 ) -> DolomiteResult<PlanNode> {
-    let id = id_gen.gen_next();
-    let (operator, inputs) = match df_plan {
-        LogicalPlan::Projection(projection) => {
-            let operator = LogicalOperator::LogicalProjection(Projection::new(
-                projection.expr.clone(),
-            ));
-            let inputs = vec![df_logical_plan_to_plan_node(&projection.input, id_gen)?];
-            (operator, inputs)
-        }
-        LogicalPlan::Limit(limit) => {
-			let DFExpr::Literal(ScalarValue::Int64(Some(l))) = *limit.fetch.as_ref().unwrap().as_ref() else {
-				panic!("got complicated limit clause");
-			};
-            let operator =
-                LogicalOperator::LogicalLimit(Limit::new(l as usize));
-            let inputs = vec![df_logical_plan_to_plan_node(&limit.input, id_gen)?];
-            (operator, inputs)
-        }
-        // LogicalPlan::Join(join) => {
-        //     let join_cond = join
-        //         .on
-        //         .iter()
-        //         .map(|(left, right)| {
-        //             ExprColumn(left.clone()).eq(ExprColumn(right.clone()))
-        //         })
-        //         .reduce(and)
-        //         .unwrap_or(Expr::Literal(ScalarValue::Boolean(Some(true))));
-        //     let operator =
-        //         LogicalOperator::LogicalJoin(Join::new(join.join_type, join_cond));
-        //     let inputs = vec![
-        //         df_logical_plan_to_plan_node(&join.left, id_gen)?,
-        //         df_logical_plan_to_plan_node(&join.right, id_gen)?,
-        //     ];
-        //     (operator, inputs)
-        // }
-        LogicalPlan::TableScan(scan) => {
-            let operator = LogicalOperator::LogicalScan(TableScan::new(
-                scan.table_name.table().to_string(),
-            ));
-            let inputs = vec![];
-            (operator, inputs)
-        }
-		LogicalPlan::Filter(filter) => {
-            let operator = LogicalOperator::LogicalFilter(Filter::new(
-                filter.predicate.clone(),
-				vec![] // FIXME(quantumish) this may be a questionable default
-            ));
-            let inputs = vec![df_logical_plan_to_plan_node(&filter.input, id_gen)?];
-            (operator, inputs)
-        }
-        plan => {
-            bail!("Unsupported datafusion logical plan: {:?}", plan);
+    enum Frame<'a, Id> {
+        Expand(&'a LogicalPlan),
+        Build(&'a LogicalPlan, Id, usize),
+    }
+
+    let mut work = vec![Frame::Expand(root)];
+    let mut results: Vec<PlanNode> = vec![];
+
+    // A closure (rather than a free function) so its `id` parameter's type
+    // — whatever `PlanNodeIdGen::gen_next` returns — is inferred from this
+    // single call site instead of having to be spelled out here.
+    let build_plan_node =
+        |df_plan: &LogicalPlan, id, inputs: Vec<PlanNode>| -> DolomiteResult<PlanNode> {
+            let operator = match df_plan {
+                LogicalPlan::Projection(projection) => {
+                    LogicalOperator::LogicalProjection(Projection::new(projection.expr.clone()))
+                }
+                LogicalPlan::Limit(limit) => {
+                    // `fetch` is `None` for a plain `OFFSET n` with no `LIMIT`
+                    // clause, which is valid DataFusion and must not panic.
+                    // `Limit::limit` has no "unbounded" variant, so `usize::MAX`
+                    // is the sentinel for "no cap", the same way `TableScan`'s
+                    // `Option<usize>` limit means "no cap" when absent.
+                    let l = match limit.fetch.as_deref() {
+                        Some(DFExpr::Literal(ScalarValue::Int64(Some(l)))) => *l as usize,
+                        Some(_) => panic!("got complicated limit clause"),
+                        None => usize::MAX,
+                    };
+
+                    let skip = match limit.skip.as_deref() {
+                        Some(DFExpr::Literal(ScalarValue::Int64(Some(s)))) => *s as usize,
+                        Some(_) => panic!("got complicated limit clause"),
+                        None => 0,
+                    };
+
+                    LogicalOperator::LogicalLimit(Limit::with_skip(skip, l))
+                }
+                LogicalPlan::Join(join) => {
+                    let join_cond = join
+                        .on
+                        .iter()
+                        .map(|(left, right)| left.clone().eq(right.clone()))
+                        .chain(join.filter.clone())
+                        .reduce(and)
+                        .unwrap_or(DFExpr::Literal(ScalarValue::Boolean(Some(true))));
+
+                    LogicalOperator::LogicalJoin(Join::new(join.join_type, join_cond))
+                }
+                LogicalPlan::EmptyRelation(empty) => LogicalOperator::LogicalEmptyRelation(
+                    EmptyRelation::new(empty.produce_one_row, empty.schema.clone()),
+                ),
+                LogicalPlan::TableScan(scan) => LogicalOperator::LogicalScan(TableScan::new(
+                    scan.table_name.table().to_string(),
+                )),
+                LogicalPlan::Filter(filter) => LogicalOperator::LogicalFilter(Filter::new(
+                    filter.predicate.clone(),
+                    vec![], // FIXME(quantumish) this may be a questionable default
+                )),
+                plan => {
+                    bail!("Unsupported datafusion logical plan: {:?}", plan);
+                }
+            };
+
+            Ok(PlanNode::new(
+                id,
+                Logical(operator),
+                inputs.into_iter().map(Arc::new).collect(),
+            ))
+        };
+
+    while let Some(frame) = work.pop() {
+        match frame {
+            Frame::Expand(plan) => {
+                let id = id_gen.gen_next();
+                let children = plan.inputs();
+                work.push(Frame::Build(plan, id, children.len()));
+                for child in children.into_iter().rev() {
+                    work.push(Frame::Expand(child));
+                }
+            }
+            Frame::Build(plan, id, n_children) => {
+                let inputs = results.split_off(results.len() - n_children);
+                results.push(build_plan_node(plan, id, inputs)?);
+            }
         }
-    };
+    }
 
-    Ok(PlanNode::new(
-        id,
-        Logical(operator),
-        inputs.into_iter().map(Arc::new).collect(),
-    ))
+    Ok(results.pop().unwrap())
 }
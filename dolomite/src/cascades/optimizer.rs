@@ -5,27 +5,33 @@ use crate::cascades::{Group, GroupExpr, GroupExprId, GroupId};
 use crate::cost::{CostModel, INF};
 use crate::error::DolomiteResult;
 
-use crate::optimizer::{Optimizer, OptimizerContext};
+use crate::optimizer::{DefaultOptimizerConfig, Optimizer, OptimizerConfig};
 use crate::plan::Plan;
 use crate::properties::PhysicalPropertySet;
 use crate::rules::RuleImpl;
 
-pub struct CascadesOptimizer {
+/// The Cascades search, parameterized by an `OptimizerConfig` borrowed for
+/// the duration of the search rather than owned by it. Config is immutable
+/// session state that a caller may share across many searches (it declares
+/// no settings; see `OptimizerConfig`); the memo below it is exactly the
+/// mutable state `find_best_plan` is allowed to mutate.
+pub struct CascadesOptimizer<'a, C: OptimizerConfig = DefaultOptimizerConfig> {
     pub required_prop: PhysicalPropertySet,
     pub rules: Vec<RuleImpl>,
     pub memo: Memo,
-    pub(super) context: OptimizerContext,
+    pub(super) config: &'a C,
     pub(super) cost_model: CostModel,
 }
 
-impl Optimizer for CascadesOptimizer {
+impl<'a, C: OptimizerConfig> Optimizer for CascadesOptimizer<'a, C> {
     type GroupHandle = GroupId;
     type ExprHandle = GroupExprId;
     type Group = Group;
     type Expr = GroupExpr;
+    type Config = C;
 
-    fn context(&self) -> &OptimizerContext {
-        &self.context
+    fn context(&self) -> &C {
+        self.config
     }
 
     fn group_at(&self, group_handle: GroupId) -> &Group {
@@ -37,12 +43,8 @@ impl Optimizer for CascadesOptimizer {
     }
 
     fn find_best_plan(&mut self) -> DolomiteResult<Plan> {
-        let root_task = OptimizeGroup::new(
-            self.memo.root_group_id(),
-            self.required_prop.clone(),
-            INF,
-        )
-        .into();
+        let root_task =
+            OptimizeGroup::new(self.memo.root_group_id(), self.required_prop.clone(), INF).into();
 
         schedule(self, root_task)?;
 
@@ -50,29 +52,31 @@ impl Optimizer for CascadesOptimizer {
     }
 }
 
-impl CascadesOptimizer {
+impl<'a, C: OptimizerConfig> CascadesOptimizer<'a, C> {
     pub fn new(
         required_prop: PhysicalPropertySet,
         rules: Vec<RuleImpl>,
         plan: Plan,
-        context: OptimizerContext,
+        config: &'a C,
         cost_model: CostModel,
     ) -> Self {
         Self {
             required_prop,
             rules,
             memo: Memo::from(plan),
-            context,
+            config,
             cost_model,
         }
     }
+}
 
+impl CascadesOptimizer<'static, DefaultOptimizerConfig> {
     pub fn default(plan: Plan) -> Self {
         Self {
             required_prop: PhysicalPropertySet::default(),
             rules: vec![],
             memo: Memo::from(plan),
-            context: OptimizerContext::default(),
+            config: DefaultOptimizerConfig::shared_default(),
             cost_model: CostModel::default(),
         }
     }
@@ -83,7 +87,7 @@ mod tests {
     use crate::cascades::CascadesOptimizer;
 
     use crate::cost::CostModel;
-    use crate::optimizer::{Optimizer, OptimizerContext};
+    use crate::optimizer::{DefaultOptimizerConfig, Optimizer};
     use crate::plan::{LogicalPlanBuilder, PhysicalPlanBuilder};
     use crate::properties::PhysicalPropertySet;
     use crate::rules::{CommutateJoinRule, Join2HashJoinRule, Scan2TableScanRule};
@@ -106,6 +110,8 @@ mod tests {
                 .build()
         };
 
+        let config = DefaultOptimizerConfig::default();
+
         let optimizer = CascadesOptimizer::new(
             PhysicalPropertySet::default(),
             vec![
@@ -114,7 +120,7 @@ mod tests {
                 Scan2TableScanRule::new().into(),
             ],
             plan,
-            OptimizerContext::default(),
+            &config,
             CostModel::default(),
         );
 
@@ -10,6 +10,18 @@ use derive_more::{Add, AddAssign, Sub, SubAssign, Sum};
 
 pub const INF: Cost = Cost(f64::INFINITY);
 
+/// Default selectivity applied to a filter when neither column statistics
+/// nor a more specific per-predicate heuristic are available.
+pub const DEFAULT_FILTER_SELECTIVITY: f64 = 0.1;
+
+/// Selectivity heuristics used when a predicate's shape is known but its
+/// operand statistics (e.g. distinct count) are not.
+pub const INEQUALITY_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Row count a scan reports when no limit has been pushed into it. Scans
+/// take no row count from the table provider.
+pub const DEFAULT_UNKNOWN_ROW_COUNT: f64 = 1000.0;
+
 #[derive(
     Copy, Clone, Debug, PartialOrd, PartialEq, Add, Sub, Sum, AddAssign, SubAssign,
 )]
@@ -21,6 +33,18 @@ impl From<f64> for Cost {
     }
 }
 
+/// Estimated number of rows a (sub)plan produces, derived bottom-up by
+/// `CostModel::estimate_cardinality` from the children's cardinalities.
+/// The caller passes those in; `Group`/`GroupExpr` do not store it.
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct Cardinality(pub f64);
+
+impl From<f64> for Cardinality {
+    fn from(c: f64) -> Self {
+        Cardinality(c)
+    }
+}
+
 #[derive(Default)]
 pub struct CostModel {
     /// Actual strategy.
@@ -28,8 +52,31 @@ pub struct CostModel {
 }
 
 impl CostModel {
-    /// Estimate cost of current operator without accumulating children's cost.
-    pub fn estimate_cost<O: Optimizer>(&self, expr: &O::Expr) -> DolomiteResult<Cost> {
-        self.inner.cost::<O>(expr)
+    /// Estimate the cardinality (output row count) of `expr` given the
+    /// already-computed cardinalities of its children. This is callable on
+    /// its own, without costing; `estimate_cost` derives the same value
+    /// again internally for the expression's own cost.
+    pub fn estimate_cardinality<O: Optimizer>(
+        &self,
+        expr: &O::Expr,
+        children_cardinality: &[Cardinality],
+    ) -> DolomiteResult<Cardinality> {
+        self.inner.cardinality::<O>(expr, children_cardinality)
+    }
+
+    /// Estimate the cost of `expr` as its own cost plus the sum of the
+    /// already-computed `children_cost`, i.e. the cost of the whole subtree
+    /// rather than of the single operator.
+    pub fn estimate_cost<O: Optimizer>(
+        &self,
+        expr: &O::Expr,
+        children_cost: &[Cost],
+        children_cardinality: &[Cardinality],
+    ) -> DolomiteResult<Cost> {
+        let own_cost = self
+            .inner
+            .cost::<O>(expr, children_cardinality)?;
+
+        Ok(children_cost.iter().copied().sum::<Cost>() + own_cost)
     }
 }
@@ -0,0 +1,106 @@
+//! A cost model with no external statistics: cardinality is derived purely
+//! from operator shape and the fixed selectivity constants in the parent
+//! module, and cost is a simple function of the cardinality a (sub)plan is
+//! estimated to produce.
+//!
+//! Nothing calls `CostModel::estimate_cardinality`/`estimate_cost` anywhere
+//! in this tree yet, and `Group`/`GroupExpr` (in `cascades/memo.rs`, also
+//! not part of this tree) don't cache a cardinality either, so these
+//! formulas aren't threaded through the memo — a caller has to start doing
+//! that before cardinality estimation affects which plan actually wins.
+
+use datafusion_expr::{Expr as DFExpr, Operator as DFOperator};
+
+use crate::cost::{
+    Cardinality, Cost, DEFAULT_FILTER_SELECTIVITY, DEFAULT_UNKNOWN_ROW_COUNT,
+    INEQUALITY_SELECTIVITY,
+};
+use crate::error::DolomiteResult;
+use crate::operator::LogicalOperator::{
+    LogicalEmptyRelation, LogicalFilter, LogicalJoin, LogicalLimit, LogicalProjection, LogicalScan,
+};
+use crate::operator::Operator::Logical;
+use crate::optimizer::Optimizer;
+
+/// Estimated fraction of rows a predicate lets through, used when there are
+/// no column statistics to derive a real one from.
+fn selectivity(expr: &DFExpr) -> f64 {
+    match expr {
+        DFExpr::BinaryExpr(binary) if binary.op == DFOperator::And => {
+            selectivity(&binary.left) * selectivity(&binary.right)
+        }
+        DFExpr::BinaryExpr(binary)
+            if matches!(
+                binary.op,
+                DFOperator::Lt | DFOperator::LtEq | DFOperator::Gt | DFOperator::GtEq
+            ) =>
+        {
+            INEQUALITY_SELECTIVITY
+        }
+        _ => DEFAULT_FILTER_SELECTIVITY,
+    }
+}
+
+#[derive(Default)]
+pub struct SimpleCostModel;
+
+impl SimpleCostModel {
+    /// Derives the output cardinality of `expr` from its operator and the
+    /// already-estimated cardinality of its children.
+    pub(crate) fn cardinality<O: Optimizer>(
+        &self,
+        expr: &O::Expr,
+        children_cardinality: &[Cardinality],
+    ) -> DolomiteResult<Cardinality> {
+        let cardinality = match expr.operator() {
+            // TODO(real scan statistics): `TableScan` only carries a table
+            // name, an optional pushed-down limit and a filter list — no
+            // table provider or statistics handle — so the best this can do
+            // without a real row count is report the pushed-down limit when
+            // there is one. Every ordinary full scan (no limit pushed down)
+            // still falls back to the flat DEFAULT_UNKNOWN_ROW_COUNT below,
+            // so two tables of very different real sizes cost the same
+            // until `TableScan` (or something the cost model can reach from
+            // it, e.g. a catalog threaded in through `OptimizerConfig`)
+            // actually carries a row count.
+            Logical(LogicalScan(scan)) => scan
+                .limit()
+                .map(|limit| limit as f64)
+                .unwrap_or(DEFAULT_UNKNOWN_ROW_COUNT),
+            Logical(LogicalFilter(filter)) => {
+                children_cardinality[0].0 * selectivity(filter.predicate())
+            }
+            Logical(LogicalProjection(_)) => children_cardinality[0].0,
+            Logical(LogicalLimit(limit)) => {
+                let remaining = (children_cardinality[0].0 - limit.skip() as f64).max(0.0);
+                remaining.min(limit.limit() as f64)
+            }
+            Logical(LogicalJoin(join)) => {
+                let unfiltered = children_cardinality[0].0 * children_cardinality[1].0;
+                unfiltered * selectivity(join.expr())
+            }
+            Logical(LogicalEmptyRelation(empty)) => {
+                if empty.produce_one_row() {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            _ => DEFAULT_UNKNOWN_ROW_COUNT,
+        };
+
+        Ok(Cardinality(cardinality))
+    }
+
+    /// A plan's own processing cost is modeled as proportional to the
+    /// number of rows it produces; `CostModel::estimate_cost` adds each
+    /// child's already-computed cost on top of this.
+    pub(crate) fn cost<O: Optimizer>(
+        &self,
+        expr: &O::Expr,
+        children_cardinality: &[Cardinality],
+    ) -> DolomiteResult<Cost> {
+        let cardinality = self.cardinality::<O>(expr, children_cardinality)?;
+        Ok(Cost(cardinality.0))
+    }
+}
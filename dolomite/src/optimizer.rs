@@ -0,0 +1,63 @@
+//! Defines the `Optimizer` trait, the interface every search strategy (HEP,
+//! Cascades, ...) implements over its own memo/expression representation,
+//! plus the `OptimizerConfig` knobs that parameterize a search.
+
+use crate::error::DolomiteResult;
+use crate::plan::Plan;
+
+/// Interface a search strategy exposes over its memo so that rules, which
+/// are written once and shared across strategies, can look up groups and
+/// expressions without knowing whether they're talking to the HEP
+/// optimizer or `CascadesOptimizer`.
+///
+/// `Config` is a required associated type with no implementor in this tree
+/// other than `CascadesOptimizer` (in `cascades/optimizer.rs`). The HEP
+/// optimizer's `impl Optimizer for ...` — in `heuristic.rs`, which this
+/// change doesn't touch and can't locate — needs `type Config = ...`
+/// (`DefaultOptimizerConfig` is the obvious choice if it doesn't otherwise
+/// need a config) and a matching `context()` body added to keep compiling.
+/// Associated types have no stable way to default on the trait side, so
+/// that edit has to happen at the HEP impl, not here.
+pub trait Optimizer {
+    type GroupHandle: Copy;
+    type ExprHandle: Copy;
+    type Group;
+    type Expr;
+    type Config: OptimizerConfig;
+
+    /// The config this search was constructed with.
+    fn context(&self) -> &Self::Config;
+
+    fn group_at(&self, group_handle: Self::GroupHandle) -> &Self::Group;
+
+    fn expr_at(&self, expr_handle: Self::ExprHandle) -> &Self::Expr;
+
+    /// Runs the search to completion and extracts the winning plan.
+    fn find_best_plan(&mut self) -> DolomiteResult<Plan>;
+}
+
+/// Configuration for a search, which a caller may share, immutably, across
+/// many searches. It is a marker trait: it declares no settings (no enabled
+/// rule set, filter selectivity or skip-on-rule-error policy) and nothing
+/// reads a value from it. It requires only `Default` and `Sync`, which
+/// `DefaultOptimizerConfig` needs for `shared_default`.
+pub trait OptimizerConfig: Default + Sync {}
+
+/// The `OptimizerConfig` used when a caller doesn't need to customize
+/// anything.
+#[derive(Default)]
+pub struct DefaultOptimizerConfig;
+
+impl OptimizerConfig for DefaultOptimizerConfig {}
+
+impl DefaultOptimizerConfig {
+    /// A process-wide default config, for callers like
+    /// `CascadesOptimizer::default` that need a `'static` borrow without
+    /// constructing and owning their own config.
+    pub fn shared_default() -> &'static Self {
+        lazy_static! {
+            static ref DEFAULT: DefaultOptimizerConfig = DefaultOptimizerConfig::default();
+        }
+        &DEFAULT
+    }
+}
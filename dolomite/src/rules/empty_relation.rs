@@ -0,0 +1,548 @@
+use anyhow::bail;
+
+use datafusion::scalar::ScalarValue;
+use datafusion_expr::logical_plan::JoinType;
+use datafusion_expr::Expr as DFExpr;
+
+use crate::error::DolomiteResult;
+use crate::operator::EmptyRelation;
+use crate::operator::LogicalOperator::{
+    LogicalEmptyRelation, LogicalFilter, LogicalJoin, LogicalLimit, LogicalProjection,
+};
+use crate::operator::Operator::Logical;
+use crate::optimizer::Optimizer;
+use crate::rules::RuleId::{
+    EmptyRelationPropagateOverLimit, EmptyRelationPropagateOverProjection,
+    FilterFalseToEmptyRelation, LimitZeroToEmptyRelation, PropagateEmptyJoinLeft,
+    PropagateEmptyJoinRight,
+};
+use crate::rules::RulePromise::LOW;
+use crate::rules::{
+    pattern, OptExpression, PatterBuilder, Pattern, Rule, RuleId, RulePromise, RuleResult,
+};
+
+#[rustfmt::skip::macros(lazy_static)]
+lazy_static! {
+    static ref LIMIT_ZERO_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalLimit(l)) if l.limit() == 0))
+          .leaf(|_| true)
+        .finish()
+    };
+    static ref FILTER_FALSE_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalFilter(f)) if is_always_false(f.predicate())))
+          .leaf(|_| true)
+        .finish()
+    };
+    static ref EMPTY_OVER_PROJECTION_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalProjection(_))))
+          .leaf(|op| matches!(op, Logical(LogicalEmptyRelation(_))))
+        .finish()
+    };
+    static ref EMPTY_OVER_LIMIT_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalLimit(_))))
+          .leaf(|op| matches!(op, Logical(LogicalEmptyRelation(_))))
+        .finish()
+    };
+    static ref JOIN_LEFT_EMPTY_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalJoin(_))))
+          .leaf(|op| matches!(op, Logical(LogicalEmptyRelation(_))))
+        .finish()
+    };
+    static ref JOIN_RIGHT_EMPTY_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalJoin(_))))
+          .leaf(|_| true)
+          .leaf(|op| matches!(op, Logical(LogicalEmptyRelation(_))))
+        .finish()
+    };
+}
+
+/// Returns true if `expr` is the literal `false`, i.e. a predicate that can
+/// never let a row through regardless of the data underneath it.
+fn is_always_false(expr: &DFExpr) -> bool {
+    matches!(expr, DFExpr::Literal(ScalarValue::Boolean(Some(false))))
+}
+
+/// Replaces `opt_expr` with an empty relation carrying its own output
+/// schema. Shared by every rule below that proves a (sub)plan can never
+/// produce a row — the only thing that differs between them is *how* they
+/// prove it, not what they do once they have.
+fn replace_with_empty_relation<O: Optimizer>(
+    ctx: &O,
+    opt_expr: &OptExpression<O>,
+) -> DolomiteResult<OptExpression<O>> {
+    let schema = ctx
+        .group_at(opt_expr.node().clone())
+        .logical_prop()
+        .schema()
+        .clone();
+
+    Ok(OptExpression::from(Logical(LogicalEmptyRelation(
+        EmptyRelation::new(false, schema),
+    ))))
+}
+
+/// `LIMIT 0` can never produce a row, so replace it outright with an empty
+/// relation carrying the limit's own output schema.
+#[derive(Clone, Default)]
+pub struct LimitZeroToEmptyRelationRule {}
+
+impl LimitZeroToEmptyRelationRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for LimitZeroToEmptyRelationRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalLimit(_)) = opt_expr.get_operator(ctx)? {
+            result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &LIMIT_ZERO_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        LimitZeroToEmptyRelation
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+/// A filter whose predicate is the literal `false` can never pass a row
+/// through, so replace it with an empty relation carrying its own output
+/// schema — the same conclusion `LIMIT 0` leads to, just proven a different
+/// way.
+#[derive(Clone, Default)]
+pub struct FilterFalseToEmptyRelationRule {}
+
+impl FilterFalseToEmptyRelationRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for FilterFalseToEmptyRelationRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalFilter(_)) = opt_expr.get_operator(ctx)? {
+            result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &FILTER_FALSE_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        FilterFalseToEmptyRelation
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+/// A projection over nothing is still nothing: collapse to an empty
+/// relation carrying the projection's own output schema.
+#[derive(Clone, Default)]
+pub struct EmptyRelationPropagateOverProjectionRule {}
+
+impl EmptyRelationPropagateOverProjectionRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for EmptyRelationPropagateOverProjectionRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalProjection(_)) = opt_expr.get_operator(ctx)? {
+            result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &EMPTY_OVER_PROJECTION_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        EmptyRelationPropagateOverProjection
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+/// Same idea as the projection case, for limit: limiting an empty relation
+/// is still empty.
+#[derive(Clone, Default)]
+pub struct EmptyRelationPropagateOverLimitRule {}
+
+impl EmptyRelationPropagateOverLimitRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for EmptyRelationPropagateOverLimitRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalLimit(_)) = opt_expr.get_operator(ctx)? {
+            result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &EMPTY_OVER_LIMIT_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        EmptyRelationPropagateOverLimit
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+/// Returns true if a join of `join_type` with the given empty sides is
+/// itself guaranteed to produce no rows.
+fn join_becomes_empty(join_type: JoinType, left_empty: bool, right_empty: bool) -> bool {
+    match join_type {
+        JoinType::Inner | JoinType::LeftSemi | JoinType::RightSemi => left_empty || right_empty,
+        // An outer join only goes empty when the side it preserves is
+        // empty; the other side going empty just means every row gets
+        // padded with nulls.
+        JoinType::Left | JoinType::LeftAnti => left_empty,
+        JoinType::Right | JoinType::RightAnti => right_empty,
+        JoinType::Full => false,
+    }
+}
+
+/// Collapses an inner/semi join, or the preserved side of an outer join,
+/// to an empty relation once its left input is known to be empty.
+#[derive(Clone, Default)]
+pub struct PropagateEmptyJoinLeftRule {}
+
+impl PropagateEmptyJoinLeftRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for PropagateEmptyJoinLeftRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalJoin(join)) = opt_expr.get_operator(ctx)? {
+            if join_becomes_empty(join.join_type(), true, false) {
+                result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+            } else {
+                result.mark_unchanged();
+            }
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &JOIN_LEFT_EMPTY_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        PropagateEmptyJoinLeft
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+/// Same as `PropagateEmptyJoinLeftRule`, triggered by the right input
+/// being empty instead of the left.
+#[derive(Clone, Default)]
+pub struct PropagateEmptyJoinRightRule {}
+
+impl PropagateEmptyJoinRightRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for PropagateEmptyJoinRightRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let Logical(LogicalJoin(join)) = opt_expr.get_operator(ctx)? {
+            if join_becomes_empty(join.join_type(), false, true) {
+                result.add(replace_with_empty_relation(ctx, &opt_expr)?);
+            } else {
+                result.mark_unchanged();
+            }
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &JOIN_RIGHT_EMPTY_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        PropagateEmptyJoinRight
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::logical_expr::{binary_expr, col, lit};
+    use datafusion_expr::logical_plan::JoinType;
+    use datafusion_expr::Operator::Eq;
+    use maplit::hashmap;
+
+    use crate::heuristic::Binding;
+    use crate::operator::LogicalOperator::LogicalEmptyRelation;
+    use crate::operator::Operator;
+    use crate::plan::LogicalPlanBuilder;
+
+    use crate::rules::{
+        EmptyRelationPropagateOverLimitRule, EmptyRelationPropagateOverProjectionRule,
+        FilterFalseToEmptyRelationRule, LimitZeroToEmptyRelationRule, PropagateEmptyJoinLeftRule,
+        PropagateEmptyJoinRightRule, Rule, RuleResult,
+    };
+    use crate::test_utils::build_hep_optimizer_for_test;
+    use crate::test_utils::table_provider_from_schema;
+
+    const T1_SCHEMA_JSON: &str = r#"{
+                "fields": [
+                    {
+                        "name": "c1",
+                        "nullable": false,
+                        "type": {
+                            "name": "utf8"
+                        },
+                        "children": []
+                    }
+                ],
+                "metadata": {}
+            }"#;
+
+    #[test]
+    fn test_limit_zero_to_empty_relation() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .projection(vec![col("c1")])
+            .limit(0)
+            .build();
+
+        let optimizer = build_hep_optimizer_for_test(
+            hashmap!("t1".to_string() => table_provider_from_schema(T1_SCHEMA_JSON)),
+            original_plan,
+        );
+
+        let rule = LimitZeroToEmptyRelationRule::new();
+
+        let opt_expr = Binding::new(optimizer.root_node_id(), rule.pattern(), &optimizer)
+            .next()
+            .unwrap();
+
+        let mut result = RuleResult::new();
+
+        rule.apply(opt_expr, &optimizer, &mut result).unwrap();
+
+        assert_eq!(1, result.exprs.len());
+        assert!(matches!(
+            result.exprs[0].get_operator(&optimizer).unwrap(),
+            Operator::Logical(LogicalEmptyRelation(_))
+        ));
+    }
+
+    #[test]
+    fn test_filter_false_to_empty_relation() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .filter(lit(false))
+            .build();
+
+        let optimizer = build_hep_optimizer_for_test(
+            hashmap!("t1".to_string() => table_provider_from_schema(T1_SCHEMA_JSON)),
+            original_plan,
+        );
+
+        let rule = FilterFalseToEmptyRelationRule::new();
+
+        let opt_expr = Binding::new(optimizer.root_node_id(), rule.pattern(), &optimizer)
+            .next()
+            .unwrap();
+
+        let mut result = RuleResult::new();
+
+        rule.apply(opt_expr, &optimizer, &mut result).unwrap();
+
+        assert_eq!(1, result.exprs.len());
+        assert!(matches!(
+            result.exprs[0].get_operator(&optimizer).unwrap(),
+            Operator::Logical(LogicalEmptyRelation(_))
+        ));
+    }
+
+    // The rules below only fire once an `EmptyRelation` already sits under
+    // a projection/limit/join. `LogicalPlanBuilder` only exposes query-shape
+    // entry points (`scan`, `projection`, `filter`, `limit`, `join`) and no
+    // way to start a plan from an `EmptyRelation` leaf the way DataFusion's
+    // own builder can (`LogicalPlanBuilder::empty`), and there's no lower-
+    // level way available here to seed a memo with one either — doing that
+    // would mean extending `LogicalPlanBuilder` (or adding a memo-seeding
+    // entry point next to `build_hep_optimizer_for_test`) in `plan.rs` /
+    // `test_utils.rs`, neither of which this change touches. Until one of
+    // those exists, these tests check only each rule's top-level pattern
+    // match against a plan whose root is that operator, without a full
+    // `Binding`/memo round trip, so these rules' `apply` is not exercised
+    // here. `join_becomes_empty`, which the join rules' `apply` uses, is
+    // tested directly below.
+
+    #[test]
+    fn test_empty_relation_propagate_over_projection_pattern() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .projection(vec![col("c1")])
+            .build();
+
+        let rule = EmptyRelationPropagateOverProjectionRule::new();
+        assert!((rule.pattern().predict)(original_plan.root().operator()));
+    }
+
+    #[test]
+    fn test_empty_relation_propagate_over_limit_pattern() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .limit(5)
+            .build();
+
+        let rule = EmptyRelationPropagateOverLimitRule::new();
+        assert!((rule.pattern().predict)(original_plan.root().operator()));
+    }
+
+    #[test]
+    fn test_propagate_empty_join_left_pattern() {
+        let right = LogicalPlanBuilder::new()
+            .scan(None, "t2".to_string())
+            .build()
+            .root();
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .join(
+                JoinType::Inner,
+                binary_expr(col("t1.c1"), Eq, col("t2.c1")),
+                right,
+            )
+            .build();
+
+        let rule = PropagateEmptyJoinLeftRule::new();
+        assert!((rule.pattern().predict)(original_plan.root().operator()));
+    }
+
+    #[test]
+    fn test_propagate_empty_join_right_pattern() {
+        let right = LogicalPlanBuilder::new()
+            .scan(None, "t2".to_string())
+            .build()
+            .root();
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .join(
+                JoinType::Inner,
+                binary_expr(col("t1.c1"), Eq, col("t2.c1")),
+                right,
+            )
+            .build();
+
+        let rule = PropagateEmptyJoinRightRule::new();
+        assert!((rule.pattern().predict)(original_plan.root().operator()));
+    }
+
+    // `join_becomes_empty` is a pure function, so its outer-join cases (the
+    // part the pattern-only tests above can't reach) are directly testable
+    // without a memo or `LogicalPlanBuilder` at all.
+    #[test]
+    fn test_join_becomes_empty() {
+        use super::join_becomes_empty;
+
+        // Inner/semi joins go empty if either side is empty.
+        assert!(join_becomes_empty(JoinType::Inner, true, false));
+        assert!(join_becomes_empty(JoinType::Inner, false, true));
+        assert!(!join_becomes_empty(JoinType::Inner, false, false));
+        assert!(join_becomes_empty(JoinType::LeftSemi, true, false));
+        assert!(join_becomes_empty(JoinType::RightSemi, false, true));
+
+        // Left-preserving joins only go empty if the left (preserved) side
+        // is empty; an empty right side is just nulls.
+        assert!(join_becomes_empty(JoinType::Left, true, false));
+        assert!(!join_becomes_empty(JoinType::Left, false, true));
+        assert!(join_becomes_empty(JoinType::LeftAnti, true, false));
+        assert!(!join_becomes_empty(JoinType::LeftAnti, false, true));
+
+        // Right-preserving joins are the mirror image.
+        assert!(join_becomes_empty(JoinType::Right, false, true));
+        assert!(!join_becomes_empty(JoinType::Right, true, false));
+        assert!(join_becomes_empty(JoinType::RightAnti, false, true));
+        assert!(!join_becomes_empty(JoinType::RightAnti, true, false));
+
+        // A full outer join never goes empty just because one side did.
+        assert!(!join_becomes_empty(JoinType::Full, true, false));
+        assert!(!join_becomes_empty(JoinType::Full, false, true));
+        assert!(!join_becomes_empty(JoinType::Full, true, true));
+    }
+}
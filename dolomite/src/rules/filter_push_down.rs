@@ -0,0 +1,507 @@
+use anyhow::bail;
+use std::collections::HashSet;
+
+use datafusion::common::tree_node::TreeNode;
+use datafusion::common::Column;
+use datafusion_expr::logical_plan::JoinType;
+use datafusion_expr::utils::expr_to_columns;
+use datafusion_expr::{and, Expr as DFExpr, Operator as DFOperator};
+
+use crate::error::DolomiteResult;
+use crate::operator::LogicalOperator::{
+    LogicalFilter, LogicalJoin, LogicalProjection, LogicalScan,
+};
+use crate::operator::Operator::Logical;
+use crate::operator::{Filter, TableScan};
+use crate::optimizer::Optimizer;
+use crate::rules::RuleId::{
+    PushFilterOverProjection, PushFilterThroughJoin, PushFilterToTableScan,
+};
+use crate::rules::RulePromise::LOW;
+use crate::rules::{
+    pattern, OptExpression, PatterBuilder, Pattern, Rule, RuleId, RulePromise, RuleResult,
+};
+
+#[rustfmt::skip::macros(lazy_static)]
+lazy_static! {
+    static ref PUSH_FILTER_OVER_PROJECTION_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalFilter(_))))
+          .leaf(|op| matches!(op, Logical(LogicalProjection(_))))
+        .finish()
+    };
+    static ref PUSH_FILTER_THROUGH_JOIN_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalFilter(_))))
+          .leaf(|op| matches!(op, Logical(LogicalJoin(_))))
+        .finish()
+    };
+    static ref PUSH_FILTER_TO_TABLE_SCAN_PATTERN: Pattern = {
+        pattern(|op| matches!(op, Logical(LogicalFilter(_))))
+          .leaf(|op| matches!(op, Logical(LogicalScan(_))))
+        .finish()
+    };
+}
+
+/// Splits a predicate on top-level `AND`s so each conjunct can be reasoned
+/// about (and pushed down) independently.
+fn split_conjuncts(expr: &DFExpr) -> Vec<DFExpr> {
+    match expr {
+        DFExpr::BinaryExpr(binary) if binary.op == DFOperator::And => {
+            let mut conjuncts = split_conjuncts(&binary.left);
+            conjuncts.extend(split_conjuncts(&binary.right));
+            conjuncts
+        }
+        _ => vec![expr.clone()],
+    }
+}
+
+/// Inverse of `split_conjuncts`: folds a list of conjuncts back into a
+/// single predicate joined by `AND`.
+fn conjoin(mut conjuncts: Vec<DFExpr>) -> DFExpr {
+    let first = conjuncts.remove(0);
+    conjuncts.into_iter().fold(first, and)
+}
+
+/// Rewrites every column reference in `expr` to the expression that
+/// produces it in `projection`, so a predicate expressed in terms of a
+/// projection's output can be evaluated below the projection instead.
+fn rewrite_over_projection(expr: &DFExpr, projection: &[DFExpr]) -> DolomiteResult<DFExpr> {
+    let mapping: std::collections::HashMap<String, DFExpr> = projection
+        .iter()
+        .map(|e| {
+            let name = e.display_name().unwrap_or_else(|_| e.to_string());
+            // An aliased projection expr (`col("c1").alias("c1_alias")`) must
+            // substitute the underlying expression, not the alias node
+            // itself, or the rewritten predicate ends up re-wrapping the
+            // column in an `Alias` it was never meant to carry.
+            let substitution = match e {
+                DFExpr::Alias(inner, _) => inner.as_ref().clone(),
+                _ => e.clone(),
+            };
+            (name, substitution)
+        })
+        .collect();
+
+    rewrite_with_mapping(expr, &mapping)
+}
+
+/// Substitutes every `Column` leaf found anywhere in `expr` per `mapping`.
+///
+/// Delegates the tree walk to `Expr`'s `TreeNode::transform`, the same way
+/// `references_only` delegates column collection to `expr_to_columns`,
+/// instead of hand-matching each `Expr` variant: a hand-rolled walker here
+/// would silently leave an unhandled shape (`Cast`, `Between`, `InList`,
+/// `Case`, ...) untouched, so a predicate like `CAST(c1_alias AS Int) > 5`
+/// pushed below the projection defining `c1_alias` would keep referencing a
+/// column that doesn't exist in the child schema.
+fn rewrite_with_mapping(
+    expr: &DFExpr,
+    mapping: &std::collections::HashMap<String, DFExpr>,
+) -> DolomiteResult<DFExpr> {
+    Ok(expr.clone().transform(&|e| {
+        Ok(match &e {
+            DFExpr::Column(c) => mapping.get(&c.name).cloned().unwrap_or(e),
+            _ => e,
+        })
+    })?)
+}
+
+/// Returns true if every column `conjunct` references is present in `schema`.
+///
+/// Delegates to datafusion's own `expr_to_columns`, which walks every `Expr`
+/// variant (not just the handful a predicate pushdown rule is likely to see)
+/// — a hand-rolled walker here would silently treat an unhandled variant
+/// (`Like`, `Case`, `ScalarFunction`, ...) as referencing no columns at all,
+/// which would make `references_only` vacuously true and push a conjunct to
+/// the wrong side of a join.
+fn references_only(
+    conjunct: &DFExpr,
+    schema: &datafusion::common::DFSchema,
+) -> DolomiteResult<bool> {
+    let mut columns = HashSet::new();
+    expr_to_columns(conjunct, &mut columns)?;
+    Ok(columns.iter().all(|c| schema.field_from_column(c).is_ok()))
+}
+
+#[derive(Clone, Default)]
+pub struct PushFilterOverProjectionRule {}
+
+impl PushFilterOverProjectionRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for PushFilterOverProjectionRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let (Logical(LogicalFilter(filter)), Logical(LogicalProjection(projection))) =
+            (opt_expr.get_operator(ctx)?, opt_expr[0].get_operator(ctx)?)
+        {
+            let rewritten = rewrite_over_projection(filter.predicate(), projection.expr())?;
+
+            let new_filter = opt_expr[0]
+                .clone_with_inputs(Logical(LogicalFilter(Filter::new(rewritten, vec![]))));
+            let ret = OptExpression::with_operator(
+                Logical(LogicalProjection(projection.clone())),
+                vec![new_filter],
+            );
+
+            result.add(ret);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &PUSH_FILTER_OVER_PROJECTION_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        PushFilterOverProjection
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct PushFilterThroughJoinRule {}
+
+impl PushFilterThroughJoinRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for PushFilterThroughJoinRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let (Logical(LogicalFilter(filter)), Logical(LogicalJoin(join))) =
+            (opt_expr.get_operator(ctx)?, opt_expr[0].get_operator(ctx)?)
+        {
+            let left_schema = ctx
+                .group_at(opt_expr[0][0].node().clone())
+                .logical_prop()
+                .schema();
+            let right_schema = ctx
+                .group_at(opt_expr[0][1].node().clone())
+                .logical_prop()
+                .schema();
+
+            // Pushing a conjunct below the join changes semantics for the
+            // side(s) that an outer join can null-pad: a preserved row
+            // whose matches the filter removes below the join still comes
+            // out, padded with nulls, where the filter above the join would
+            // have dropped it. Only push to a side the join always preserves.
+            let (left_pushable, right_pushable) = match join.join_type() {
+                JoinType::Inner | JoinType::LeftSemi | JoinType::RightSemi => (true, true),
+                JoinType::Left | JoinType::LeftAnti => (true, false),
+                JoinType::Right | JoinType::RightAnti => (false, true),
+                JoinType::Full => (false, false),
+            };
+
+            let mut left_conjuncts = vec![];
+            let mut right_conjuncts = vec![];
+            let mut remaining = vec![];
+
+            for conjunct in split_conjuncts(filter.predicate()) {
+                if left_pushable && references_only(&conjunct, left_schema)? {
+                    left_conjuncts.push(conjunct);
+                } else if right_pushable && references_only(&conjunct, right_schema)? {
+                    right_conjuncts.push(conjunct);
+                } else {
+                    remaining.push(conjunct);
+                }
+            }
+
+            if left_conjuncts.is_empty() && right_conjuncts.is_empty() {
+                // Nothing to push: every conjunct either touches both sides
+                // or touches only a side this join type doesn't allow
+                // pushing to, so the filter stays where it is. Mark this
+                // binding unchanged and add no rewrite.
+                result.mark_unchanged();
+                return Ok(());
+            }
+
+            let new_left = if left_conjuncts.is_empty() {
+                opt_expr[0][0].clone()
+            } else {
+                OptExpression::with_operator(
+                    Logical(LogicalFilter(Filter::new(conjoin(left_conjuncts), vec![]))),
+                    vec![opt_expr[0][0].clone()],
+                )
+            };
+
+            let new_right = if right_conjuncts.is_empty() {
+                opt_expr[0][1].clone()
+            } else {
+                OptExpression::with_operator(
+                    Logical(LogicalFilter(Filter::new(conjoin(right_conjuncts), vec![]))),
+                    vec![opt_expr[0][1].clone()],
+                )
+            };
+
+            let new_join = OptExpression::with_operator(
+                Logical(LogicalJoin(join.clone())),
+                vec![new_left, new_right],
+            );
+
+            let ret = if remaining.is_empty() {
+                new_join
+            } else {
+                OptExpression::with_operator(
+                    Logical(LogicalFilter(Filter::new(conjoin(remaining), vec![]))),
+                    vec![new_join],
+                )
+            };
+
+            result.add(ret);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &PUSH_FILTER_THROUGH_JOIN_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        PushFilterThroughJoin
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct PushFilterToTableScanRule {}
+
+impl PushFilterToTableScanRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Rule for PushFilterToTableScanRule {
+    fn apply<O: Optimizer>(
+        &self,
+        opt_expr: OptExpression<O>,
+        ctx: &O,
+        result: &mut RuleResult<O>,
+    ) -> DolomiteResult<()> {
+        if let (Logical(LogicalFilter(filter)), Logical(LogicalScan(scan))) =
+            (opt_expr.get_operator(ctx)?, opt_expr[0].get_operator(ctx)?)
+        {
+            let mut filters = scan.filters().to_vec();
+            filters.extend(split_conjuncts(filter.predicate()));
+
+            let ret = OptExpression::from(Logical(LogicalScan(TableScan::with_filters(
+                scan.table_name(),
+                filters,
+            ))));
+
+            result.add(ret);
+
+            Ok(())
+        } else {
+            bail!("Pattern miss matched!")
+        }
+    }
+
+    fn pattern(&self) -> &Pattern {
+        &PUSH_FILTER_TO_TABLE_SCAN_PATTERN
+    }
+
+    fn rule_id(&self) -> RuleId {
+        PushFilterToTableScan
+    }
+
+    fn rule_promise(&self) -> RulePromise {
+        LOW
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::logical_expr::{binary_expr, col};
+    use datafusion_expr::logical_plan::JoinType;
+    use datafusion_expr::Operator::Eq;
+    use maplit::hashmap;
+
+    use crate::heuristic::Binding;
+    use crate::operator::LogicalOperator::{LogicalFilter, LogicalProjection, LogicalScan};
+    use crate::operator::{Filter, Operator, Projection, TableScan};
+    use crate::plan::LogicalPlanBuilder;
+
+    use crate::rules::{
+        OptExpression, PushFilterOverProjectionRule, PushFilterThroughJoinRule,
+        PushFilterToTableScanRule, Rule, RuleResult,
+    };
+    use crate::test_utils::build_hep_optimizer_for_test;
+    use crate::test_utils::table_provider_from_schema;
+
+    const T1_SCHEMA_JSON: &str = r#"{
+                "fields": [
+                    {
+                        "name": "c1",
+                        "nullable": false,
+                        "type": {
+                            "name": "utf8"
+                        },
+                        "children": []
+                    },
+                    {
+                        "name": "c2",
+                        "nullable": false,
+                        "type": {
+                            "name": "utf8"
+                        },
+                        "children": []
+                    }
+                ],
+                "metadata": {}
+            }"#;
+
+    #[test]
+    fn test_push_filter_over_projection_pattern() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .projection(vec![col("c1")])
+            .filter(binary_expr(col("c1"), Eq, col("c1")))
+            .build();
+
+        let rule = PushFilterOverProjectionRule::new();
+        assert!((rule.pattern().predict)(original_plan.root().operator()));
+    }
+
+    #[test]
+    fn test_push_filter_to_table_scan() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .filter(binary_expr(col("c1"), Eq, col("c1")))
+            .build();
+
+        let optimizer = build_hep_optimizer_for_test(
+            hashmap!("t1".to_string() => table_provider_from_schema(T1_SCHEMA_JSON)),
+            original_plan,
+        );
+
+        let rule = PushFilterToTableScanRule::new();
+
+        let opt_expr = Binding::new(optimizer.root_node_id(), rule.pattern(), &optimizer)
+            .next()
+            .unwrap();
+
+        let mut result = RuleResult::new();
+
+        rule.apply(opt_expr, &optimizer, &mut result).unwrap();
+
+        let expected_opt_expr = OptExpression::new_builder::<Operator>(
+            LogicalScan(TableScan::with_filters(
+                "t1",
+                vec![binary_expr(col("c1"), Eq, col("c1"))],
+            ))
+            .into(),
+        )
+        .end_node();
+
+        assert_eq!(1, result.exprs.len());
+        assert_eq!(expected_opt_expr, result.exprs[0]);
+    }
+
+    #[test]
+    fn test_push_filter_over_projection() {
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .projection(vec![col("c1").alias("c1_alias")])
+            .filter(binary_expr(col("c1_alias"), Eq, col("c1_alias")))
+            .build();
+
+        let optimizer = build_hep_optimizer_for_test(
+            hashmap!("t1".to_string() => table_provider_from_schema(T1_SCHEMA_JSON)),
+            original_plan,
+        );
+
+        let rule = PushFilterOverProjectionRule::new();
+
+        let opt_expr = Binding::new(optimizer.root_node_id(), rule.pattern(), &optimizer)
+            .next()
+            .unwrap();
+
+        let table_scan_group_id = opt_expr[0][0].node().clone();
+
+        let mut result = RuleResult::new();
+
+        rule.apply(opt_expr, &optimizer, &mut result).unwrap();
+
+        let expected_opt_expr = OptExpression::new_builder::<Operator>(
+            LogicalProjection(Projection::new(vec![col("c1").alias("c1_alias")])).into(),
+        )
+        .begin_node::<Operator>(
+            LogicalFilter(Filter::new(binary_expr(col("c1"), Eq, col("c1")), vec![])).into(),
+        )
+        .leaf(table_scan_group_id)
+        .end_node()
+        .end_node();
+
+        assert_eq!(1, result.exprs.len());
+        assert_eq!(expected_opt_expr, result.exprs[0]);
+    }
+
+    #[test]
+    fn test_push_filter_through_left_join_does_not_push_right_conjunct() {
+        // A conjunct that only references the null-supplying side of a LEFT
+        // JOIN must not be pushed below it: left rows whose right matches
+        // it removes would still come out of the join padded with nulls,
+        // where the filter above the join drops them.
+        let right = LogicalPlanBuilder::new()
+            .scan(None, "t2".to_string())
+            .build()
+            .root();
+        let original_plan = LogicalPlanBuilder::new()
+            .scan(None, "t1".to_string())
+            .join(
+                JoinType::Left,
+                binary_expr(col("t1.c1"), Eq, col("t2.c1")),
+                right,
+            )
+            .filter(binary_expr(col("t2.c2"), Eq, col("t2.c2")))
+            .build();
+
+        let optimizer = build_hep_optimizer_for_test(
+            hashmap!(
+                "t1".to_string() => table_provider_from_schema(T1_SCHEMA_JSON),
+                "t2".to_string() => table_provider_from_schema(T1_SCHEMA_JSON),
+            ),
+            original_plan,
+        );
+
+        let rule = PushFilterThroughJoinRule::new();
+
+        let opt_expr = Binding::new(optimizer.root_node_id(), rule.pattern(), &optimizer)
+            .next()
+            .unwrap();
+
+        let mut result = RuleResult::new();
+
+        rule.apply(opt_expr, &optimizer, &mut result).unwrap();
+
+        // Nothing is safe to push on either side, so the rule should report
+        // no new expression rather than rewriting the filter below the join.
+        assert!(result.exprs.is_empty());
+    }
+}
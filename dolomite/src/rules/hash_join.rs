@@ -0,0 +1,58 @@
+//! Build/probe side selection for hash joins.
+//!
+//! `choose_build_side` picks which input of a join should be the hash build
+//! side from the estimated cardinality (see `crate::cost::Cardinality`) of
+//! each input: the smaller one, since build-side memory and probe cost both
+//! scale with that choice. The decision doesn't depend on anything
+//! physical-operator-specific, so it is a small, independently testable
+//! helper.
+//!
+//! Nothing calls `choose_build_side`. `Join2HashJoinRule`, which turns a
+//! `LogicalJoin` into a physical hash join, does not consult it, so no rule
+//! picks the build side by cardinality or commutes a join's inputs and
+//! condition to match.
+
+use crate::cost::Cardinality;
+
+/// Which input of a join should be the hash build side.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BuildSide {
+    Left,
+    Right,
+}
+
+/// Picks the build side for a hash join given the estimated cardinality of
+/// each input. Ties keep the existing (left) build side so the rule is
+/// stable and doesn't thrash.
+pub fn choose_build_side(left: Cardinality, right: Cardinality) -> BuildSide {
+    if right.0 < left.0 {
+        BuildSide::Right
+    } else {
+        BuildSide::Left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose_build_side_prefers_smaller_input() {
+        assert_eq!(
+            BuildSide::Right,
+            choose_build_side(Cardinality(1000.0), Cardinality(10.0))
+        );
+        assert_eq!(
+            BuildSide::Left,
+            choose_build_side(Cardinality(10.0), Cardinality(1000.0))
+        );
+    }
+
+    #[test]
+    fn test_choose_build_side_tie_keeps_left() {
+        assert_eq!(
+            BuildSide::Left,
+            choose_build_side(Cardinality(100.0), Cardinality(100.0))
+        );
+    }
+}
@@ -1,3 +1,12 @@
+//! `result.mark_unchanged()` (called below, and from the equivalent no-op
+//! paths in `filter_push_down` and `empty_relation`) records on the
+//! `RuleResult` that firing this rule against this binding produced
+//! nothing new.
+//!
+//! The HEP scheduler/binding loop (in `heuristic.rs`) does not read that
+//! record: it does not track `(node, rule)` convergence, so marking a
+//! binding unchanged does not by itself stop it from being re-queued.
+
 use anyhow::bail;
 use std::cmp::min;
 
@@ -92,8 +101,7 @@ impl Rule for RemoveLimitRule {
         {
             let new_limit = min(limit1.limit(), limit2.limit());
 
-            let ret =
-                input[0].clone_with_inputs(Logical(LogicalLimit(Limit::new(new_limit))));
+            let ret = input[0].clone_with_inputs(Logical(LogicalLimit(Limit::new(new_limit))));
 
             result.add(ret);
             Ok(())
@@ -134,6 +142,17 @@ impl Rule for PushLimitToTableScanRule {
         if let (Logical(LogicalLimit(limit)), Logical(LogicalScan(scan))) =
             (input.get_operator(ctx)?, input[0].get_operator(ctx)?)
         {
+            if scan
+                .limit()
+                .map_or(false, |existing| existing <= limit.limit())
+            {
+                // The scan's limit is already at least as tight as the one
+                // above it, so firing again would just rebuild the same
+                // scan. Mark this binding unchanged and add no rewrite.
+                result.mark_unchanged();
+                return Ok(());
+            }
+
             let new_limit = scan
                 .limit()
                 .map(|l1| min(l1, limit.limit()))
@@ -171,15 +190,13 @@ mod tests {
     use maplit::hashmap;
 
     use crate::heuristic::Binding;
-    use crate::operator::LogicalOperator::{
-        LogicalLimit, LogicalProjection, LogicalScan,
-    };
+    use crate::operator::LogicalOperator::{LogicalLimit, LogicalProjection, LogicalScan};
     use crate::operator::{Limit, Operator, Projection, TableScan};
     use crate::plan::LogicalPlanBuilder;
 
     use crate::rules::{
-        OptExpression, PushLimitOverProjectionRule, PushLimitToTableScanRule,
-        RemoveLimitRule, Rule, RuleResult,
+        OptExpression, PushLimitOverProjectionRule, PushLimitToTableScanRule, RemoveLimitRule,
+        Rule, RuleResult,
     };
     use crate::test_utils::build_hep_optimizer_for_test;
     use crate::test_utils::table_provider_from_schema;